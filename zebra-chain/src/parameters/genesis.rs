@@ -6,8 +6,9 @@ use super::network::AllParameters as _;
 
 /// The previous block hash for the genesis block.
 ///
-/// All known networks use the Bitcoin `null` value for the parent of the
-/// genesis block. (In Bitcoin, `null` is `[0; 32]`.)
+/// All known networks, including [`Network::Regtest`], use the Bitcoin
+/// `null` value for the parent of the genesis block. (In Bitcoin, `null` is
+/// `[0; 32]`.)
 pub const GENESIS_PREVIOUS_BLOCK_HASH: block::Hash = block::Hash([0; 32]);
 
 /// Returns the hash for the genesis block in `network`.
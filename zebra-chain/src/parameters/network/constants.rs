@@ -3,5 +3,37 @@ use crate::block::Height;
 /// The first halving height in the testnet is at block height `1_116_000`
 /// as specified in [protocol specification ยง7.10.1][7.10.1]
 ///
+/// This is the same height that `Network::Testnet.halving_height(1)`
+/// computes from the testnet Blossom activation height.
+///
 /// [7.10.1]: https://zips.z.cash/protocol/protocol.pdf#zip214fundingstreams
 pub const FIRST_HALVING_TESTNET: Height = Height(1_116_000);
+
+/// The start of the funding stream and halving calculations, relative to the
+/// genesis block, as specified in
+/// [protocol specification ยง7.10.1][7.10.1].
+///
+/// This is defined as half of the slow-start interval (the number of blocks
+/// during which the block subsidy ramps up linearly from zero), which is
+/// `20_000` blocks.
+///
+/// [7.10.1]: https://zips.z.cash/protocol/protocol.pdf#zip214fundingstreams
+pub const SLOW_START_SHIFT: Height = Height(10_000);
+
+/// The number of blocks between halvings, before the Blossom network upgrade,
+/// as specified in [ZIP-208](https://zips.z.cash/zip-0208).
+pub const PRE_BLOSSOM_HALVING_INTERVAL: u32 = 840_000;
+
+/// The number of blocks between halvings, from the Blossom network upgrade
+/// onward.
+///
+/// Blossom halves the block target interval, so this is double the
+/// pre-Blossom interval, which keeps the halving schedule on the same
+/// wall-clock cadence, as specified in [ZIP-208](https://zips.z.cash/zip-0208).
+pub const POST_BLOSSOM_HALVING_INTERVAL: u32 = PRE_BLOSSOM_HALVING_INTERVAL * 2;
+
+/// The height at which the Blossom network upgrade activates on Mainnet.
+pub const BLOSSOM_ACTIVATION_HEIGHT_MAINNET: Height = Height(653_600);
+
+/// The height at which the Blossom network upgrade activates on the default Testnet.
+pub const BLOSSOM_ACTIVATION_HEIGHT_TESTNET: Height = Height(584_000);
@@ -0,0 +1,206 @@
+//! Consensus parameters for each Zcash network.
+
+pub mod constants;
+
+use crate::block::{self, Height};
+
+use self::constants::{
+    BLOSSOM_ACTIVATION_HEIGHT_MAINNET, BLOSSOM_ACTIVATION_HEIGHT_TESTNET,
+    POST_BLOSSOM_HALVING_INTERVAL, PRE_BLOSSOM_HALVING_INTERVAL, SLOW_START_SHIFT,
+};
+
+/// An enum describing the possible network choices.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Network {
+    /// The production mainnet.
+    Mainnet,
+    /// The default public testnet.
+    Testnet,
+    /// A local, solo-mined development network.
+    ///
+    /// Regtest has no peers and no shared history with Mainnet or Testnet: a
+    /// node starts it from its own genesis block and activates every network
+    /// upgrade at height 0, so it's only useful for local testing.
+    Regtest,
+}
+
+/// The hash of the genesis block on mainnet, as a fixed byte array.
+///
+/// <https://explorer.zcha.in/blocks/00040fe8ec8471911baa1db1266ea15dd06b4a8a5c453883c000b031973dce08>
+const MAINNET_GENESIS_HASH: [u8; 32] = [
+    0x08, 0xce, 0x3d, 0x97, 0x31, 0xb0, 0x00, 0xc0, 0x83, 0x38, 0x45, 0x5c, 0x8a, 0x4a, 0x6b, 0xd0,
+    0x5d, 0xa1, 0x6e, 0x26, 0xb1, 0x1d, 0xaa, 0x1b, 0x91, 0x71, 0x84, 0xec, 0xe8, 0x0f, 0x04, 0x00,
+];
+
+/// The hash of the genesis block on the default testnet, as a fixed byte array.
+///
+/// <https://explorer.testnet.z.cash/blocks/05a60a92d99d85997cce3b87616c089f6124d7342af37106edc76126334a2c38>
+const TESTNET_GENESIS_HASH: [u8; 32] = [
+    0x38, 0x2c, 0x4a, 0x33, 0x26, 0x61, 0xc7, 0xed, 0x06, 0x71, 0xf3, 0x2a, 0x34, 0xd7, 0x24, 0x61,
+    0x9f, 0x08, 0x6c, 0x61, 0x87, 0x3b, 0xce, 0x7c, 0x99, 0x85, 0x9d, 0xd9, 0x92, 0x0a, 0xa6, 0x05,
+];
+
+/// The hash of the genesis block on Regtest, as a fixed byte array.
+///
+/// Unlike Mainnet and Testnet, this isn't pinned by any shared history: a
+/// Regtest chain only needs to agree with itself, so any fixed value works.
+const REGTEST_GENESIS_HASH: [u8; 32] = [
+    0x20, 0xe2, 0x66, 0x14, 0xa1, 0xb1, 0x90, 0xa5, 0xfc, 0x2a, 0x01, 0x36, 0xb4, 0xbe, 0xf5, 0x8b,
+    0x32, 0xfc, 0xa4, 0xe3, 0x35, 0xa3, 0xf2, 0x71, 0x2c, 0x7b, 0xcb, 0x13, 0x8f, 0x18, 0xf9, 0x00,
+];
+
+/// Consensus parameters that differ per network.
+///
+/// Only the genesis hash is looked up through this trait. Activation heights
+/// and the cached vector maps are inherent [`Network`] methods that `match`
+/// on every variant, so each of them has its own [`Network::Regtest`] arm.
+pub trait AllParameters {
+    /// Returns the hash of the genesis block for this network.
+    fn genesis_hash(&self) -> block::Hash;
+}
+
+impl AllParameters for Network {
+    fn genesis_hash(&self) -> block::Hash {
+        match self {
+            Network::Mainnet => block::Hash(MAINNET_GENESIS_HASH),
+            Network::Testnet => block::Hash(TESTNET_GENESIS_HASH),
+            Network::Regtest => block::Hash(REGTEST_GENESIS_HASH),
+        }
+    }
+}
+
+impl Network {
+    /// Returns true if network is of type Mainnet.
+    pub fn is_mainnet(&self) -> bool {
+        match self {
+            Network::Mainnet => true,
+            Network::Testnet | Network::Regtest => false,
+        }
+    }
+
+    /// Returns true if network is the default public Testnet.
+    pub fn is_default_testnet(&self) -> bool {
+        match self {
+            Network::Testnet => true,
+            Network::Mainnet | Network::Regtest => false,
+        }
+    }
+
+    /// Returns true if network is Regtest.
+    pub fn is_regtest(&self) -> bool {
+        match self {
+            Network::Regtest => true,
+            Network::Mainnet | Network::Testnet => false,
+        }
+    }
+
+    /// Returns the height at which the Blossom network upgrade activates on this network.
+    ///
+    /// Regtest activates every network upgrade at genesis, so Blossom is active from height 0.
+    fn blossom_activation_height(&self) -> Height {
+        match self {
+            Network::Mainnet => BLOSSOM_ACTIVATION_HEIGHT_MAINNET,
+            Network::Testnet => BLOSSOM_ACTIVATION_HEIGHT_TESTNET,
+            Network::Regtest => Height(0),
+        }
+    }
+
+    /// Twice the number of blocks between the slow start shift and this network's Blossom
+    /// activation height.
+    ///
+    /// This is doubled (rather than divided down to a halving count) so that it shares a
+    /// denominator with [`POST_BLOSSOM_HALVING_INTERVAL`], which is exactly twice
+    /// [`PRE_BLOSSOM_HALVING_INTERVAL`]. That lets [`Network::halving_index`] and
+    /// [`Network::halving_height`] floor the *combined* pre- and post-Blossom progress as a
+    /// single fraction, instead of flooring each side separately and losing the fractional
+    /// halving that was already accrued before Blossom activated.
+    fn doubled_pre_blossom_progress_at_blossom_activation(&self) -> u32 {
+        2 * self
+            .blossom_activation_height()
+            .0
+            .saturating_sub(SLOW_START_SHIFT.0)
+    }
+
+    /// Returns the number of halvings that have occurred by `height` on this network, following
+    /// the schedule in [ZIP-208](https://zips.z.cash/zip-0208).
+    ///
+    /// This is the inverse of [`Network::halving_height`].
+    pub fn halving_index(&self, height: Height) -> u32 {
+        let blossom_height = self.blossom_activation_height();
+
+        if height < blossom_height {
+            height.0.saturating_sub(SLOW_START_SHIFT.0) / PRE_BLOSSOM_HALVING_INTERVAL
+        } else {
+            let doubled_progress = self.doubled_pre_blossom_progress_at_blossom_activation()
+                + (height.0 - blossom_height.0);
+            doubled_progress / POST_BLOSSOM_HALVING_INTERVAL
+        }
+    }
+
+    /// Returns the height of the first block of the `n`th halving on this network, or `None` if
+    /// the height would overflow. This is the inverse of [`Network::halving_index`].
+    pub fn halving_height(&self, n: u32) -> Option<Height> {
+        let blossom_height = self.blossom_activation_height();
+
+        let pre_blossom_height =
+            SLOW_START_SHIFT.0.checked_add(n.checked_mul(PRE_BLOSSOM_HALVING_INTERVAL)?)?;
+
+        let height = if pre_blossom_height < blossom_height.0 {
+            pre_blossom_height
+        } else {
+            let doubled_progress = n.checked_mul(POST_BLOSSOM_HALVING_INTERVAL)?;
+            let doubled_pre_blossom_progress =
+                self.doubled_pre_blossom_progress_at_blossom_activation();
+
+            blossom_height
+                .0
+                .checked_add(doubled_progress.saturating_sub(doubled_pre_blossom_progress))?
+        };
+
+        Some(Height(height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halving_index_and_height_round_trip() {
+        for network in [Network::Mainnet, Network::Testnet, Network::Regtest] {
+            for n in 0..5 {
+                let height = network
+                    .halving_height(n)
+                    .expect("halving height should not overflow for small n");
+                assert_eq!(network.halving_index(height), n);
+            }
+        }
+    }
+
+    #[test]
+    fn halving_index_is_zero_during_slow_start() {
+        assert_eq!(Network::Mainnet.halving_index(Height(0)), 0);
+        assert_eq!(Network::Testnet.halving_index(Height(0)), 0);
+    }
+
+    /// The first halving height is a fixed, well-known value on each network: it must match
+    /// `BLOCK_MAINNET_1046400_BYTES` on Mainnet, and [`constants::FIRST_HALVING_TESTNET`] on
+    /// Testnet. A bug that floors the pre- and post-Blossom halving progress separately (instead
+    /// of as a combined fraction) silently passes `halving_index_and_height_round_trip`, so these
+    /// concrete heights are checked explicitly here.
+    #[test]
+    fn first_halving_height_matches_known_values() {
+        assert_eq!(Network::Mainnet.halving_height(1), Some(Height(1_046_400)));
+        assert_eq!(Network::Testnet.halving_height(1), Some(Height(1_116_000)));
+    }
+
+    #[test]
+    fn regtest_activates_blossom_at_genesis() {
+        // Regtest's Blossom height is 0, so even its first halving uses the
+        // post-Blossom interval.
+        assert_eq!(
+            Network::Regtest.halving_height(1),
+            Some(Height(POST_BLOSSOM_HALVING_INTERVAL))
+        );
+    }
+}
@@ -4,103 +4,206 @@
 
 use std::collections::BTreeMap;
 
+use once_cell::sync::Lazy;
+
 use crate::{
-    block::Block,
+    block::{Block, Height},
     parameters::Network,
+    sapling,
     serialization::{SerializationError, ZcashDeserializeInto},
 };
 
 use zebra_test::vectors::{
-    BLOCK_MAINNET_1046400_BYTES, BLOCK_MAINNET_653599_BYTES, BLOCK_MAINNET_982681_BYTES,
-    BLOCK_TESTNET_1116000_BYTES, BLOCK_TESTNET_583999_BYTES, BLOCK_TESTNET_925483_BYTES,
-    CONTINUOUS_MAINNET_BLOCKS, CONTINUOUS_TESTNET_BLOCKS, MAINNET_BLOCKS,
-    MAINNET_FINAL_SAPLING_ROOTS, MAINNET_FINAL_SPROUT_ROOTS,
+    BLOCK_MAINNET_1046400_BYTES, BLOCK_MAINNET_1687104_BYTES, BLOCK_MAINNET_653599_BYTES,
+    BLOCK_MAINNET_982681_BYTES, BLOCK_TESTNET_1116000_BYTES, BLOCK_TESTNET_583999_BYTES,
+    BLOCK_TESTNET_925483_BYTES, CONTINUOUS_MAINNET_BLOCKS, CONTINUOUS_TESTNET_BLOCKS,
+    MAINNET_BLOCKS, MAINNET_FINAL_ORCHARD_ROOTS, MAINNET_FINAL_SAPLING_ROOTS,
+    MAINNET_FINAL_SPROUT_ROOTS, ORCHARD_FINAL_ROOT_MAINNET_1687104_BYTES,
     SAPLING_FINAL_ROOT_MAINNET_1046400_BYTES, SAPLING_FINAL_ROOT_TESTNET_1116000_BYTES,
-    TESTNET_BLOCKS, TESTNET_FINAL_SAPLING_ROOTS, TESTNET_FINAL_SPROUT_ROOTS,
+    TESTNET_BLOCKS, TESTNET_FINAL_ORCHARD_ROOTS, TESTNET_FINAL_SAPLING_ROOTS,
+    TESTNET_FINAL_SPROUT_ROOTS,
 };
 
+/// Regtest has no upstream cached vectors: it's a local-only network, so
+/// there's no shared chain to pin test fixtures to. These maps stand in for
+/// the `zebra_test::vectors` statics used by Mainnet and Testnet below.
+static REGTEST_BLOCKS: Lazy<BTreeMap<u32, &'static [u8]>> = Lazy::new(BTreeMap::new);
+static REGTEST_FINAL_SAPLING_ROOTS: Lazy<BTreeMap<u32, &'static [u8; 32]>> =
+    Lazy::new(BTreeMap::new);
+static REGTEST_FINAL_SPROUT_ROOTS: Lazy<BTreeMap<u32, &'static [u8; 32]>> =
+    Lazy::new(BTreeMap::new);
+static REGTEST_FINAL_ORCHARD_ROOTS: Lazy<BTreeMap<u32, &'static [u8; 32]>> =
+    Lazy::new(BTreeMap::new);
+
+/// The single cached blocks for Mainnet, keyed by height instead of a pair of
+/// per-network magic numbers. This is what [`Network::cached_block`] and its
+/// `_with_*_root` siblings look callers up in, instead of each taking its own
+/// `main_bytes`/`test_bytes` pair.
+static MAINNET_CACHED_BLOCKS: Lazy<BTreeMap<Height, &'static [u8]>> = Lazy::new(|| {
+    [
+        (Height(653_599), &BLOCK_MAINNET_653599_BYTES[..]),
+        (Height(982_681), &BLOCK_MAINNET_982681_BYTES[..]),
+        (Height(1_046_400), &BLOCK_MAINNET_1046400_BYTES[..]),
+        (Height(1_687_104), &BLOCK_MAINNET_1687104_BYTES[..]),
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// The single cached blocks for the default Testnet, keyed by height. See
+/// [`MAINNET_CACHED_BLOCKS`].
+static TESTNET_CACHED_BLOCKS: Lazy<BTreeMap<Height, &'static [u8]>> = Lazy::new(|| {
+    [
+        (Height(583_999), &BLOCK_TESTNET_583999_BYTES[..]),
+        (Height(925_483), &BLOCK_TESTNET_925483_BYTES[..]),
+        (Height(1_116_000), &BLOCK_TESTNET_1116000_BYTES[..]),
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Regtest has no single cached blocks of its own yet.
+static REGTEST_CACHED_BLOCKS: Lazy<BTreeMap<Height, &'static [u8]>> = Lazy::new(BTreeMap::new);
+
+/// The cached Sapling final roots for the single cached blocks above, keyed by height.
+static MAINNET_CACHED_SAPLING_ROOTS: Lazy<BTreeMap<Height, &'static [u8; 32]>> =
+    Lazy::new(|| [(Height(1_046_400), &*SAPLING_FINAL_ROOT_MAINNET_1046400_BYTES)].into());
+static TESTNET_CACHED_SAPLING_ROOTS: Lazy<BTreeMap<Height, &'static [u8; 32]>> =
+    Lazy::new(|| [(Height(1_116_000), &*SAPLING_FINAL_ROOT_TESTNET_1116000_BYTES)].into());
+static REGTEST_CACHED_SAPLING_ROOTS: Lazy<BTreeMap<Height, &'static [u8; 32]>> =
+    Lazy::new(BTreeMap::new);
+
+/// The cached Orchard final roots for the single cached blocks above, keyed by height.
+static MAINNET_CACHED_ORCHARD_ROOTS: Lazy<BTreeMap<Height, &'static [u8; 32]>> =
+    Lazy::new(|| [(Height(1_687_104), &*ORCHARD_FINAL_ROOT_MAINNET_1687104_BYTES)].into());
+static TESTNET_CACHED_ORCHARD_ROOTS: Lazy<BTreeMap<Height, &'static [u8; 32]>> =
+    Lazy::new(BTreeMap::new);
+static REGTEST_CACHED_ORCHARD_ROOTS: Lazy<BTreeMap<Height, &'static [u8; 32]>> =
+    Lazy::new(BTreeMap::new);
+
 /// Network methods for fetching blockchain state.
 impl Network {
-    /// Returns true if network is of type Mainnet.
-    pub fn is_mainnet(&self) -> bool {
-        match self {
-            Network::Mainnet => true,
-            Network::Testnet => false,
-        }
-    }
-    /// Returns true if network is type default Testnet.
-    pub fn is_default_testnet(&self) -> bool {
-        match self {
-            Network::Mainnet => false,
-            Network::Testnet => true,
-        }
-    }
-
     /// Returns iterator over blocks.
-    pub fn get_block_iter(&self) -> std::collections::btree_map::Iter<'static, u32, &'static [u8]> {
-        if self.is_mainnet() {
-            MAINNET_BLOCKS.iter()
-        } else {
-            TESTNET_BLOCKS.iter()
+    pub fn get_block_iter(&self) -> std::collections::btree_map::Iter<'_, u32, &'static [u8]> {
+        match self {
+            Network::Mainnet => MAINNET_BLOCKS.iter(),
+            Network::Testnet => TESTNET_BLOCKS.iter(),
+            Network::Regtest => REGTEST_BLOCKS.iter(),
         }
     }
 
     /// Return the map of heights to blocks
     pub fn get_block_map(&self) -> &BTreeMap<u32, &'static [u8]> {
-        if self.is_mainnet() {
-            &zebra_test::vectors::MAINNET_BLOCKS
-        } else {
-            &zebra_test::vectors::TESTNET_BLOCKS
+        match self {
+            Network::Mainnet => &zebra_test::vectors::MAINNET_BLOCKS,
+            Network::Testnet => &zebra_test::vectors::TESTNET_BLOCKS,
+            Network::Regtest => &REGTEST_BLOCKS,
         }
     }
 
     /// Returns genesis block for chain.
     pub fn get_gen_block(&self) -> std::option::Option<&[u8]> {
-        if self.is_mainnet() {
-            MAINNET_BLOCKS.get(&0)
-        } else {
-            TESTNET_BLOCKS.get(&0)
+        match self {
+            Network::Mainnet => MAINNET_BLOCKS.get(&0),
+            Network::Testnet => TESTNET_BLOCKS.get(&0),
+            Network::Regtest => REGTEST_BLOCKS.get(&0),
         }
         .cloned()
     }
 
-    /// Returns block bytes
-    pub fn get_block_bytes(
+    /// Returns the single cached block at `height` on this network, deserialized.
+    ///
+    /// Unlike the old `get_block_bytes(main_bytes, test_bytes)`, callers don't need to pass a
+    /// dummy value for the network they aren't asking about, and a lookup miss always returns
+    /// the same [`SerializationError::NotACachedBlock`] regardless of network.
+    pub fn cached_block(&self, height: Height) -> Result<Block, SerializationError> {
+        self.cached_blocks()
+            .get(&height)
+            .ok_or(SerializationError::NotACachedBlock(height))?
+            .zcash_deserialize_into()
+    }
+
+    /// Returns the single cached block at `height` on this network, along with its Sapling
+    /// final root, if both are cached.
+    pub fn cached_block_with_sapling_root(
         &self,
-        main_bytes: u32,
-        test_bytes: u32,
-    ) -> Result<Block, SerializationError> {
-        if self.is_mainnet() {
-            match main_bytes {
-                653_599 => BLOCK_MAINNET_653599_BYTES.zcash_deserialize_into(),
-                982_681 => BLOCK_MAINNET_982681_BYTES.zcash_deserialize_into(),
-                _ => Err(SerializationError::NotACachedMainNetBlock(main_bytes)),
-            }
-        } else {
-            match test_bytes {
-                583_999 => BLOCK_TESTNET_583999_BYTES.zcash_deserialize_into(),
-                925_483 => BLOCK_TESTNET_925483_BYTES.zcash_deserialize_into(),
-                _ => Err(SerializationError::NotACachedTestNetBlock(test_bytes)),
-            }
+        height: Height,
+    ) -> Result<(&'static [u8], [u8; 32]), SerializationError> {
+        let block_bytes = *self
+            .cached_blocks()
+            .get(&height)
+            .ok_or(SerializationError::NotACachedBlock(height))?;
+        let sapling_root = **self
+            .cached_sapling_roots()
+            .get(&height)
+            .ok_or(SerializationError::NotACachedBlock(height))?;
+
+        Ok((block_bytes, sapling_root))
+    }
+
+    /// Returns the single cached block at `height` on this network, along with its Orchard
+    /// final root, if both are cached.
+    ///
+    /// So far, only one Mainnet block (at the NU5 activation height) has a cached Orchard root.
+    pub fn cached_block_with_orchard_root(
+        &self,
+        height: Height,
+    ) -> Result<(&'static [u8], [u8; 32]), SerializationError> {
+        let block_bytes = *self
+            .cached_blocks()
+            .get(&height)
+            .ok_or(SerializationError::NotACachedBlock(height))?;
+        let orchard_root = **self
+            .cached_orchard_roots()
+            .get(&height)
+            .ok_or(SerializationError::NotACachedBlock(height))?;
+
+        Ok((block_bytes, orchard_root))
+    }
+
+    /// Returns the single cached blocks registry for this network, keyed by height.
+    fn cached_blocks(&self) -> &'static BTreeMap<Height, &'static [u8]> {
+        match self {
+            Network::Mainnet => &MAINNET_CACHED_BLOCKS,
+            Network::Testnet => &TESTNET_CACHED_BLOCKS,
+            Network::Regtest => &REGTEST_CACHED_BLOCKS,
+        }
+    }
+
+    /// Returns the cached Sapling final roots registry for this network, keyed by height.
+    fn cached_sapling_roots(&self) -> &'static BTreeMap<Height, &'static [u8; 32]> {
+        match self {
+            Network::Mainnet => &MAINNET_CACHED_SAPLING_ROOTS,
+            Network::Testnet => &TESTNET_CACHED_SAPLING_ROOTS,
+            Network::Regtest => &REGTEST_CACHED_SAPLING_ROOTS,
+        }
+    }
+
+    /// Returns the cached Orchard final roots registry for this network, keyed by height.
+    fn cached_orchard_roots(&self) -> &'static BTreeMap<Height, &'static [u8; 32]> {
+        match self {
+            Network::Mainnet => &MAINNET_CACHED_ORCHARD_ROOTS,
+            Network::Testnet => &TESTNET_CACHED_ORCHARD_ROOTS,
+            Network::Regtest => &REGTEST_CACHED_ORCHARD_ROOTS,
         }
     }
 
     /// Returns iterator over blockchain.
     pub fn get_blockchain_iter(&self) -> std::collections::btree_map::Iter<'_, u32, &[u8]> {
-        if self.is_mainnet() {
-            CONTINUOUS_MAINNET_BLOCKS.iter()
-        } else {
-            CONTINUOUS_TESTNET_BLOCKS.iter()
+        match self {
+            Network::Mainnet => CONTINUOUS_MAINNET_BLOCKS.iter(),
+            Network::Testnet => CONTINUOUS_TESTNET_BLOCKS.iter(),
+            Network::Regtest => REGTEST_BLOCKS.iter(),
         }
     }
 
     /// Returns BTreemap of blockchain, keys are heights, and values are blocks.
     /// Why not represent as a vec?
     pub fn get_blockchain_map(&self) -> &BTreeMap<u32, &'static [u8]> {
-        if self.is_mainnet() {
-            &CONTINUOUS_MAINNET_BLOCKS
-        } else {
-            &CONTINUOUS_TESTNET_BLOCKS
+        match self {
+            Network::Mainnet => &CONTINUOUS_MAINNET_BLOCKS,
+            Network::Testnet => &CONTINUOUS_TESTNET_BLOCKS,
+            Network::Regtest => &REGTEST_BLOCKS,
         }
     }
 
@@ -111,10 +214,10 @@ impl Network {
         std::collections::btree_map::Iter<'_, u32, &[u8]>,
         std::collections::BTreeMap<u32, &[u8; 32]>,
     ) {
-        if self.is_mainnet() {
-            (MAINNET_BLOCKS.iter(), MAINNET_FINAL_SAPLING_ROOTS.clone())
-        } else {
-            (TESTNET_BLOCKS.iter(), TESTNET_FINAL_SAPLING_ROOTS.clone())
+        match self {
+            Network::Mainnet => (MAINNET_BLOCKS.iter(), MAINNET_FINAL_SAPLING_ROOTS.clone()),
+            Network::Testnet => (TESTNET_BLOCKS.iter(), TESTNET_FINAL_SAPLING_ROOTS.clone()),
+            Network::Regtest => (REGTEST_BLOCKS.iter(), REGTEST_FINAL_SAPLING_ROOTS.clone()),
         }
     }
 
@@ -125,39 +228,24 @@ impl Network {
         &std::collections::BTreeMap<u32, &'static [u8]>,
         &std::collections::BTreeMap<u32, &'static [u8; 32]>,
     ) {
-        if self.is_mainnet() {
-            (&*MAINNET_BLOCKS, &*MAINNET_FINAL_SAPLING_ROOTS)
-        } else {
-            (&*TESTNET_BLOCKS, &*TESTNET_FINAL_SAPLING_ROOTS)
+        match self {
+            Network::Mainnet => (&*MAINNET_BLOCKS, &*MAINNET_FINAL_SAPLING_ROOTS),
+            Network::Testnet => (&*TESTNET_BLOCKS, &*TESTNET_FINAL_SAPLING_ROOTS),
+            Network::Regtest => (&*REGTEST_BLOCKS, &*REGTEST_FINAL_SAPLING_ROOTS),
         }
     }
 
-    /// Returns block and sapling root bytes
-    pub fn get_block_sapling_roots_bytes(
+    /// Returns BTreemap of blocks and orchard roots.
+    pub fn get_block_orchard_roots_map(
         &self,
-        main_bytes: u32,
-        test_bytes: u32,
-    ) -> Result<(&[u8], [u8; 32]), SerializationError> {
-        if self.is_mainnet() {
-            match main_bytes {
-                1_046_400 => Ok((
-                    &BLOCK_MAINNET_1046400_BYTES[..],
-                    *SAPLING_FINAL_ROOT_MAINNET_1046400_BYTES,
-                )),
-                _ => Err(SerializationError::NotACachedMainNetSaplingRootBytes(
-                    main_bytes,
-                )),
-            }
-        } else {
-            match test_bytes {
-                1_116_000 => Ok((
-                    &BLOCK_TESTNET_1116000_BYTES[..],
-                    *SAPLING_FINAL_ROOT_TESTNET_1116000_BYTES,
-                )),
-                _ => Err(SerializationError::NotACachedTestNetSaplingRootBytes(
-                    test_bytes,
-                )),
-            }
+    ) -> (
+        &std::collections::BTreeMap<u32, &'static [u8]>,
+        &std::collections::BTreeMap<u32, &'static [u8; 32]>,
+    ) {
+        match self {
+            Network::Mainnet => (&*MAINNET_BLOCKS, &*MAINNET_FINAL_ORCHARD_ROOTS),
+            Network::Testnet => (&*TESTNET_BLOCKS, &*TESTNET_FINAL_ORCHARD_ROOTS),
+            Network::Regtest => (&*REGTEST_BLOCKS, &*REGTEST_FINAL_ORCHARD_ROOTS),
         }
     }
 
@@ -174,20 +262,123 @@ impl Network {
 
         // The testnet block height at which the first JoinSplit occurred.
         const TESTNET_FIRST_JOINSPLIT_HEIGHT: u32 = 2259;
-        if self.is_mainnet() {
-            (
+
+        // Regtest has no history, so there's no first JoinSplit height to report.
+        const REGTEST_FIRST_JOINSPLIT_HEIGHT: u32 = 0;
+
+        match self {
+            Network::Mainnet => (
                 &*MAINNET_BLOCKS,
                 &*MAINNET_FINAL_SPROUT_ROOTS,
                 MAINNET_FIRST_JOINSPLIT_HEIGHT,
-            )
-        } else {
-            (
+            ),
+            Network::Testnet => (
                 &*TESTNET_BLOCKS,
                 &*TESTNET_FINAL_SPROUT_ROOTS,
                 TESTNET_FIRST_JOINSPLIT_HEIGHT,
-            )
+            ),
+            Network::Regtest => (
+                &*REGTEST_BLOCKS,
+                &*REGTEST_FINAL_SPROUT_ROOTS,
+                REGTEST_FIRST_JOINSPLIT_HEIGHT,
+            ),
         }
     }
+
+    /// Trial-decrypts every Sapling output in this network's cached blockchain vectors (see
+    /// [`Network::get_blockchain_iter`]) against `viewing_keys`, returning every note any of
+    /// them can see.
+    ///
+    /// This is the equivalent of upstream's `decrypt_transaction`/`try_sapling_note_decryption`
+    /// and `try_sapling_output_recovery`, giving the crate a self-contained, vector-driven way
+    /// to exercise wallet-scanning logic without pulling in a full wallet backend. Blocks that
+    /// fail to deserialize are skipped, since the cached vectors span multiple network
+    /// upgrades and this is a best-effort scan, not full validation.
+    ///
+    /// This relies on `sapling::note_encryption::try_sapling_note_decryption`,
+    /// `try_sapling_output_recovery`, and `sapling::keys::FullViewingKey` taking this shape of
+    /// `&Network`/`Height`/viewing-key/output arguments; double check those signatures against
+    /// `zebra_chain::sapling` wherever it's built alongside this module, since trial decryption
+    /// isn't implemented in this trimmed snapshot.
+    pub fn scan_cached_blocks(
+        &self,
+        viewing_keys: &[sapling::keys::FullViewingKey],
+    ) -> Vec<DecryptedOutput> {
+        // Purely an optimization, not a correctness fix: the per-output loop below already
+        // yields nothing when `viewing_keys` is empty, since it has nothing to iterate over.
+        // This just skips deserializing every cached block to reach that conclusion.
+        if viewing_keys.is_empty() {
+            return Vec::new();
+        }
+
+        let mut decrypted = Vec::new();
+
+        for (&height, block_bytes) in self.get_blockchain_iter() {
+            let height = Height(height);
+            let block: Block = match block_bytes.zcash_deserialize_into() {
+                Ok(block) => block,
+                Err(_) => continue,
+            };
+
+            for transaction in block.transactions.iter() {
+                for output in transaction.sapling_outputs() {
+                    for (account, viewing_key) in viewing_keys.iter().enumerate() {
+                        // The height (and therefore network upgrade) selects the
+                        // note-encryption domain, so the same ciphertext decrypts
+                        // differently either side of a consensus branch change.
+                        if let Some((note, recipient, memo)) =
+                            sapling::note_encryption::try_sapling_note_decryption(
+                                self, height, viewing_key, output,
+                            )
+                        {
+                            decrypted.push(DecryptedOutput {
+                                height,
+                                account,
+                                note,
+                                recipient,
+                                memo,
+                                outgoing: false,
+                            });
+                        } else if let Some((note, recipient, memo)) =
+                            sapling::note_encryption::try_sapling_output_recovery(
+                                self, height, viewing_key, output,
+                            )
+                        {
+                            decrypted.push(DecryptedOutput {
+                                height,
+                                account,
+                                note,
+                                recipient,
+                                memo,
+                                outgoing: true,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        decrypted
+    }
+}
+
+/// A Sapling note belonging to one of the scanning viewing keys, decrypted from a cached block.
+#[derive(Clone, Debug)]
+pub struct DecryptedOutput {
+    /// The height of the block the decrypted output was found in.
+    pub height: Height,
+    /// The index, within the `viewing_keys` slice passed to [`Network::scan_cached_blocks`], of
+    /// the account that can view this note.
+    pub account: usize,
+    /// The decrypted note.
+    pub note: sapling::Note,
+    /// The recipient's shielded payment address.
+    pub recipient: sapling::PaymentAddress,
+    /// The decrypted 512-byte memo field.
+    pub memo: [u8; 512],
+    /// True if this note was recovered using the viewing key's outgoing half (meaning it's a
+    /// note this account sent), rather than its incoming half.
+    pub outgoing: bool,
 }
 
 #[cfg(test)]
@@ -201,7 +392,7 @@ mod tests {
     use proptest::prelude::*;
 
     fn networks() -> impl Strategy<Value = crate::parameters::Network> {
-        prop::sample::select(vec![Network::Mainnet, Network::Testnet])
+        prop::sample::select(vec![Network::Mainnet, Network::Testnet, Network::Regtest])
     }
     proptest! {
         #[test]
@@ -215,6 +406,11 @@ mod tests {
                     prop_assert!(!network.is_mainnet(), "Testnet should not return true for is_mainnet");
                     prop_assert!(network.is_default_testnet());
                 },
+                Network::Regtest => {
+                    prop_assert!(!network.is_mainnet(), "Regtest should not return true for is_mainnet");
+                    prop_assert!(!network.is_default_testnet(), "Regtest should not be default testnet");
+                    prop_assert!(network.is_regtest());
+                },
             }
         }
     }
@@ -225,57 +421,97 @@ mod tests {
     }
 
     #[test]
-    fn get_block_bytes() {
+    fn cached_block() {
         let mainnet = Network::Mainnet;
         let testnet = Network::Testnet;
 
-        let result = mainnet.get_block_bytes(0, 583999);
+        let result = mainnet.cached_block(Height(583_999));
         assert!(matches!(
             result,
-            Err(SerializationError::NotACachedMainNetBlock(0))
+            Err(SerializationError::NotACachedBlock(Height(583_999)))
         ));
-        let result = mainnet.get_block_bytes(653599, 0).unwrap();
-        let _correct_main_bytes: Block =
+        let result = mainnet.cached_block(Height(653_599)).unwrap();
+        let correct_main_bytes: Block =
             BLOCK_MAINNET_653599_BYTES.zcash_deserialize_into().unwrap();
-        assert!(matches!(result, _correct_main_bytes));
+        assert_eq!(result, correct_main_bytes);
 
-        let result = testnet.get_block_bytes(653599, 0);
+        let result = testnet.cached_block(Height(653_599));
         assert!(matches!(
             result,
-            Err(SerializationError::NotACachedTestNetBlock(0))
+            Err(SerializationError::NotACachedBlock(Height(653_599)))
         ));
-        let result = testnet.get_block_bytes(0, 583999).unwrap();
-        let _correct_test_bytes: Block =
+        let result = testnet.cached_block(Height(583_999)).unwrap();
+        let correct_test_bytes: Block =
             BLOCK_TESTNET_583999_BYTES.zcash_deserialize_into().unwrap();
-        assert!(matches!(result, _correct_test_bytes));
+        assert_eq!(result, correct_test_bytes);
     }
 
     #[test]
-    fn get_block_sapling_roots_bytes() {
+    fn cached_block_with_sapling_root() {
         let mainnet = Network::Mainnet;
         let testnet = Network::Testnet;
-        let result = mainnet.get_block_sapling_roots_bytes(0, 1116000);
+
+        let result = mainnet.cached_block_with_sapling_root(Height(1_116_000));
         assert!(matches!(
             result,
-            Err(SerializationError::NotACachedMainNetSaplingRootBytes(0))
+            Err(SerializationError::NotACachedBlock(Height(1_116_000)))
         ));
-        let result = mainnet.get_block_sapling_roots_bytes(1046400, 0).unwrap();
-        let _correct_main_result: (&[u8], [u8; 32]) = (
+        let result = mainnet
+            .cached_block_with_sapling_root(Height(1_046_400))
+            .unwrap();
+        let correct_main_result: (&[u8], [u8; 32]) = (
             &BLOCK_MAINNET_1046400_BYTES[..],
             *SAPLING_FINAL_ROOT_MAINNET_1046400_BYTES,
         );
-        assert!(matches!(result, _correct_main_result));
+        assert_eq!(result, correct_main_result);
 
-        let result = testnet.get_block_sapling_roots_bytes(1046400, 0);
+        let result = testnet.cached_block_with_sapling_root(Height(1_046_400));
         assert!(matches!(
             result,
-            Err(SerializationError::NotACachedTestNetSaplingRootBytes(0))
+            Err(SerializationError::NotACachedBlock(Height(1_046_400)))
         ));
-        let result = testnet.get_block_sapling_roots_bytes(0, 1116000).unwrap();
-        let _correct_test_result: (&[u8], [u8; 32]) = (
+        let result = testnet
+            .cached_block_with_sapling_root(Height(1_116_000))
+            .unwrap();
+        let correct_test_result: (&[u8], [u8; 32]) = (
             &BLOCK_TESTNET_1116000_BYTES[..],
             *SAPLING_FINAL_ROOT_TESTNET_1116000_BYTES,
         );
-        assert!(matches!(result, _correct_test_result));
+        assert_eq!(result, correct_test_result);
+    }
+
+    #[test]
+    fn cached_block_with_orchard_root() {
+        let mainnet = Network::Mainnet;
+        let testnet = Network::Testnet;
+
+        let result = mainnet.cached_block_with_orchard_root(Height(0));
+        assert!(matches!(
+            result,
+            Err(SerializationError::NotACachedBlock(Height(0)))
+        ));
+        let result = mainnet
+            .cached_block_with_orchard_root(Height(1_687_104))
+            .unwrap();
+        let correct_main_result: (&[u8], [u8; 32]) = (
+            &BLOCK_MAINNET_1687104_BYTES[..],
+            *ORCHARD_FINAL_ROOT_MAINNET_1687104_BYTES,
+        );
+        assert_eq!(result, correct_main_result);
+
+        let result = testnet.cached_block_with_orchard_root(Height(1_687_104));
+        assert!(matches!(
+            result,
+            Err(SerializationError::NotACachedBlock(Height(1_687_104)))
+        ));
+    }
+
+    #[test]
+    fn scan_cached_blocks_with_no_viewing_keys_finds_nothing() {
+        let mainnet = Network::Mainnet;
+
+        let decrypted = mainnet.scan_cached_blocks(&[]);
+
+        assert!(decrypted.is_empty());
     }
 }